@@ -0,0 +1,643 @@
+//! Symbols and helpers used to draw the borders of a [`Block`](crate::widgets::Block).
+//!
+//! Besides the plain [`Set`] of glyphs used to draw a single border, this module hosts the
+//! junction engine that [`Block`](crate::widgets::Block) uses to merge the border of one block
+//! with whatever is already in the buffer (see `Block::merge_with`). See [`LineParts`] for the
+//! data structure behind that engine.
+
+use std::ops::BitOr;
+
+/// The "weight" (stroke style) of a single directional segment of a border junction.
+///
+/// Weights are combined when two borders meet at the same cell; see [`LineParts`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Weight {
+    /// No stroke at all in this direction.
+    #[default]
+    None,
+    /// A plain, single-width stroke (used by [`BorderType::Plain`](crate::widgets::BorderType::Plain)
+    /// and [`BorderType::Rounded`](crate::widgets::BorderType::Rounded)).
+    Thin,
+    /// A bold, double-width stroke (used by
+    /// [`BorderType::Thick`](crate::widgets::BorderType::Thick)).
+    Thick,
+    /// A doubled-line stroke (used by
+    /// [`BorderType::Double`](crate::widgets::BorderType::Double)).
+    Double,
+}
+
+impl Weight {
+    /// Combines two weights meeting at the same junction arm.
+    ///
+    /// `None` always loses to an actual stroke. When two different real weights meet on the same
+    /// arm (e.g. a thin border drawn over a thick one), the heavier of the two wins, whichever
+    /// one was drawn first: `Double` is heavier than `Thick`, and both are heavier than `Thin`.
+    const fn combine(self, other: Self) -> Self {
+        use Weight::{Double, None, Thick, Thin};
+        match (self, other) {
+            (None, other) => other,
+            (other, None) => other,
+            (Double, _) | (_, Double) => Double,
+            (Thick, _) | (_, Thick) => Thick,
+            (Thin, Thin) => Thin,
+        }
+    }
+}
+
+/// The weight of each of the four directional arms that can meet at a single border cell.
+///
+/// This is the core data structure behind the junction engine used to merge borders. Every
+/// border cell is modeled as four segments -- `up`, `down`, `left` and `right` -- each carrying
+/// its own [`Weight`]. Two `LineParts` describing the same cell (one read back from whatever is
+/// already in the `Buffer`, one for the stroke about to be drawn) can be combined with
+/// [`BitOr`](std::ops::BitOr) to compute what the cell should look like once both are drawn, and
+/// the result can be turned back into a glyph with [`Set::symbol_from_line_parts`].
+///
+/// A direction with [`Weight::None`] is never emitted as a stroke.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct LineParts {
+    pub up: Weight,
+    pub down: Weight,
+    pub left: Weight,
+    pub right: Weight,
+}
+
+impl LineParts {
+    /// A `LineParts` with no arms at all.
+    pub const NONE: Self = Self {
+        up: Weight::None,
+        down: Weight::None,
+        left: Weight::None,
+        right: Weight::None,
+    };
+
+    /// Returns `true` if none of the four arms carry a stroke.
+    pub const fn is_empty(self) -> bool {
+        matches!(self.up, Weight::None)
+            && matches!(self.down, Weight::None)
+            && matches!(self.left, Weight::None)
+            && matches!(self.right, Weight::None)
+    }
+
+    /// Returns the single weight shared by every non-`None` arm, or `None` if the arms present
+    /// don't all agree (a "mixed-weight" junction).
+    pub fn uniform_weight(self) -> Option<Weight> {
+        [self.up, self.down, self.left, self.right]
+            .into_iter()
+            .filter(|weight| *weight != Weight::None)
+            .dedup_single()
+    }
+}
+
+/// Small helper extracted so [`LineParts::uniform_weight`] reads top-to-bottom instead of as a
+/// manual fold.
+trait DedupSingle: Iterator {
+    fn dedup_single(self) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: PartialEq + Copy,
+    {
+        let mut iter = self;
+        let first = iter.next()?;
+        iter.all(|item| item == first).then_some(first)
+    }
+}
+
+impl<I: Iterator> DedupSingle for I {}
+
+impl BitOr for LineParts {
+    type Output = Self;
+
+    /// Merges two junctions arm-by-arm, keeping the heavier [`Weight`] on each arm.
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self {
+            up: self.up.combine(rhs.up),
+            down: self.down.combine(rhs.down),
+            left: self.left.combine(rhs.left),
+            right: self.right.combine(rhs.right),
+        }
+    }
+}
+
+/// A set of eight box drawing characters and a handful of additional junction symbols used to
+/// draw a border, plus the merged tee and cross symbols used when two borders meet.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Set {
+    pub top_left: &'static str,
+    pub top_right: &'static str,
+    pub bottom_left: &'static str,
+    pub bottom_right: &'static str,
+    pub vertical_left: &'static str,
+    pub vertical_right: &'static str,
+    pub horizontal_top: &'static str,
+    pub horizontal_bottom: &'static str,
+    /// The "├" style symbol used where a vertical line has a branch pointing right.
+    pub vertical_t_right: &'static str,
+    /// The "┤" style symbol used where a vertical line has a branch pointing left.
+    pub vertical_t_left: &'static str,
+    /// The "┬" style symbol used where a horizontal line has a branch pointing down.
+    pub horizontal_t_down: &'static str,
+    /// The "┴" style symbol used where a horizontal line has a branch pointing up.
+    pub horizontal_t_up: &'static str,
+    /// The "┼" style symbol used where all four directions meet.
+    pub cross: &'static str,
+}
+
+impl Set {
+    /// The [`Weight`] of the strokes drawn by this `Set`, used as the starting point when merging
+    /// against borders of unknown provenance (e.g. a custom `Set`).
+    ///
+    /// This is inferred by comparing against the built-in sets rather than stored explicitly, so
+    /// that `Set` stays a plain bag of symbols.
+    pub(crate) fn weight(&self) -> Weight {
+        if *self == THICK {
+            Weight::Thick
+        } else if *self == DOUBLE {
+            Weight::Double
+        } else {
+            Weight::Thin
+        }
+    }
+
+    /// Returns the [`LineParts`] (all arms at this set's own [`Weight`]) that correspond to a
+    /// symbol drawn by this `Set`, or `None` if `symbol` isn't one of this set's own glyphs.
+    ///
+    /// This is checked before falling back to [`reverse_unicode_lookup`] so that a custom `Set`
+    /// built from arbitrary (non-Unicode) strings, as used for testing, still merges correctly
+    /// with itself.
+    fn own_line_parts_from_symbol(&self, symbol: &str) -> Option<LineParts> {
+        let weight = self.weight();
+        let thin = |present: bool| if present { weight } else { Weight::None };
+        let parts = if symbol == self.top_left {
+            LineParts { down: weight, right: weight, ..LineParts::NONE }
+        } else if symbol == self.top_right {
+            LineParts { down: weight, left: weight, ..LineParts::NONE }
+        } else if symbol == self.bottom_left {
+            LineParts { up: weight, right: weight, ..LineParts::NONE }
+        } else if symbol == self.bottom_right {
+            LineParts { up: weight, left: weight, ..LineParts::NONE }
+        } else if symbol == self.vertical_left || symbol == self.vertical_right {
+            LineParts { up: weight, down: weight, ..LineParts::NONE }
+        } else if symbol == self.horizontal_top || symbol == self.horizontal_bottom {
+            LineParts { left: weight, right: weight, ..LineParts::NONE }
+        } else if symbol == self.vertical_t_right {
+            LineParts { up: weight, down: weight, right: weight, left: thin(false) }
+        } else if symbol == self.vertical_t_left {
+            LineParts { up: weight, down: weight, left: weight, right: thin(false) }
+        } else if symbol == self.horizontal_t_down {
+            LineParts { left: weight, right: weight, down: weight, up: thin(false) }
+        } else if symbol == self.horizontal_t_up {
+            LineParts { left: weight, right: weight, up: weight, down: thin(false) }
+        } else if symbol == self.cross {
+            LineParts { up: weight, down: weight, left: weight, right: weight }
+        } else {
+            return None;
+        };
+        Some(parts)
+    }
+
+    /// Reads a glyph back out of the buffer and decodes which directional arms it represents.
+    ///
+    /// This first checks this `Set`'s own glyphs (see [`Self::own_line_parts_from_symbol`]), then
+    /// falls back to a table of well-known Unicode box-drawing characters so that the junction
+    /// engine can also merge against a neighbor using a different [`BorderType`](crate::widgets::BorderType).
+    pub fn line_parts_from_symbol(&self, symbol: &str) -> Option<LineParts> {
+        self.own_line_parts_from_symbol(symbol)
+            .or_else(|| reverse_unicode_lookup(symbol))
+    }
+
+    /// Resolves the glyph that should be drawn for a combined [`LineParts`] junction.
+    ///
+    /// If every non-empty arm shares this set's own [`Weight`], the matching symbol from this
+    /// `Set` is used directly (this keeps custom, non-Unicode sets working). Otherwise `parts`
+    /// describes a mixed-weight junction, and the glyph is resolved from the global Unicode
+    /// box-drawing table in [`unicode_symbol_from_line_parts`].
+    ///
+    /// The Unicode table has a glyph for every combination of two or more `Thin` and `Thick`
+    /// arms, but not for every junction that mixes in `Double` (e.g. `Double` meeting `Thick`).
+    /// When there is no entry, each arm is reduced to present or absent and the junction is drawn
+    /// with this set's own glyph for that shape, at this set's own weight; if this set has no
+    /// glyph for that shape (a single arm), its `cross` symbol is used.
+    pub fn symbol_from_line_parts(&self, parts: LineParts) -> &'static str {
+        if parts.is_empty() {
+            return " ";
+        }
+        if parts.uniform_weight() == Some(self.weight()) {
+            if let Some(symbol) = self.own_symbol_from_line_parts(parts) {
+                return symbol;
+            }
+        }
+        unicode_symbol_from_line_parts(parts).unwrap_or_else(|| {
+            // Fallback: collapse every arm down to Thin (or drop it) and resolve via this set's
+            // own glyphs, which have an entry for every shape of two or more arms.
+            let downgrade = |w: Weight| if w == Weight::None { Weight::None } else { Weight::Thin };
+            let thinned = LineParts {
+                up: downgrade(parts.up),
+                down: downgrade(parts.down),
+                left: downgrade(parts.left),
+                right: downgrade(parts.right),
+            };
+            self.own_symbol_from_line_parts(thinned)
+                .unwrap_or(self.cross)
+        })
+    }
+
+    /// The structural counterpart to [`Self::own_line_parts_from_symbol`]: picks this set's own
+    /// glyph for a uniform-weight arm combination.
+    fn own_symbol_from_line_parts(&self, parts: LineParts) -> Option<&'static str> {
+        let present = |w: Weight| w != Weight::None;
+        let (up, down, left, right) = (
+            present(parts.up),
+            present(parts.down),
+            present(parts.left),
+            present(parts.right),
+        );
+        Some(match (up, down, left, right) {
+            (false, true, false, true) => self.top_left,
+            (false, true, true, false) => self.top_right,
+            (true, false, false, true) => self.bottom_left,
+            (true, false, true, false) => self.bottom_right,
+            (true, true, false, false) => self.vertical_left,
+            (false, false, true, true) => self.horizontal_top,
+            (true, true, false, true) => self.vertical_t_right,
+            (true, true, true, false) => self.vertical_t_left,
+            (false, true, true, true) => self.horizontal_t_down,
+            (true, false, true, true) => self.horizontal_t_up,
+            (true, true, true, true) => self.cross,
+            _ => return None,
+        })
+    }
+}
+
+/// Looks up a mixed- or uniform-weight junction against the full Unicode box-drawing block.
+///
+/// Every combination of two or more `Thin` and `Thick` arms has a dedicated glyph and is listed
+/// here in full. For `Double`, only the uniform combinations, the two symmetric thin/double
+/// crossings and the thin/double corners are listed; any other junction involving `Double` (e.g.
+/// one mixing `Double` and `Thick`) has no entry and falls back to the caller's own set (see
+/// [`Set::symbol_from_line_parts`]).
+fn unicode_symbol_from_line_parts(parts: LineParts) -> Option<&'static str> {
+    use Weight::{Double, None as N, Thick, Thin};
+    let LineParts { up, down, left, right } = parts;
+    Some(match (up, down, left, right) {
+        // -- uniform straight lines --
+        (Thin, Thin, N, N) => "│",
+        (N, N, Thin, Thin) => "─",
+        (Thick, Thick, N, N) => "┃",
+        (N, N, Thick, Thick) => "━",
+        (Double, Double, N, N) => "║",
+        (N, N, Double, Double) => "═",
+        // -- uniform thin --
+        (N, Thin, N, Thin) => "┌",
+        (Thin, N, N, Thin) => "└",
+        (N, Thin, Thin, N) => "┐",
+        (Thin, N, Thin, N) => "┘",
+        (Thin, Thin, N, Thin) => "├",
+        (Thin, Thin, Thin, N) => "┤",
+        (N, Thin, Thin, Thin) => "┬",
+        (Thin, N, Thin, Thin) => "┴",
+        (Thin, Thin, Thin, Thin) => "┼",
+        // -- uniform thick --
+        (N, Thick, N, Thick) => "┏",
+        (Thick, N, N, Thick) => "┗",
+        (N, Thick, Thick, N) => "┓",
+        (Thick, N, Thick, N) => "┛",
+        (Thick, Thick, N, Thick) => "┣",
+        (Thick, Thick, Thick, N) => "┫",
+        (N, Thick, Thick, Thick) => "┳",
+        (Thick, N, Thick, Thick) => "┻",
+        (Thick, Thick, Thick, Thick) => "╋",
+        // -- uniform double --
+        (N, Double, N, Double) => "╔",
+        (Double, N, N, Double) => "╚",
+        (N, Double, Double, N) => "╗",
+        (Double, N, Double, N) => "╝",
+        (Double, Double, N, Double) => "╠",
+        (Double, Double, Double, N) => "╣",
+        (N, Double, Double, Double) => "╦",
+        (Double, N, Double, Double) => "╩",
+        (Double, Double, Double, Double) => "╬",
+        // -- thin/thick mixes: every combination of the two weights is listed per family, since
+        // Unicode defines a distinct glyph for each one --
+        // ├ family (up, down, right)
+        (Thin, Thin, N, Thick) => "┝",
+        (Thick, Thin, N, Thin) => "┞",
+        (Thin, Thick, N, Thin) => "┟",
+        (Thick, Thick, N, Thin) => "┠",
+        (Thick, Thin, N, Thick) => "┡",
+        (Thin, Thick, N, Thick) => "┢",
+        // ┤ family (up, down, left)
+        (Thin, Thin, Thick, N) => "┥",
+        (Thick, Thin, Thin, N) => "┦",
+        (Thin, Thick, Thin, N) => "┧",
+        (Thick, Thick, Thin, N) => "┨",
+        (Thick, Thin, Thick, N) => "┩",
+        (Thin, Thick, Thick, N) => "┪",
+        // ┬ family (down, left, right)
+        (N, Thin, Thick, Thin) => "┭",
+        (N, Thin, Thin, Thick) => "┮",
+        (N, Thin, Thick, Thick) => "┯",
+        (N, Thick, Thin, Thin) => "┰",
+        (N, Thick, Thick, Thin) => "┱",
+        (N, Thick, Thin, Thick) => "┲",
+        // ┴ family (up, left, right)
+        (Thin, N, Thick, Thin) => "┵",
+        (Thin, N, Thin, Thick) => "┶",
+        (Thin, N, Thick, Thick) => "┷",
+        (Thick, N, Thin, Thin) => "┸",
+        (Thick, N, Thick, Thin) => "┹",
+        (Thick, N, Thin, Thick) => "┺",
+        // ┼ family (up, down, left, right)
+        (Thin, Thin, Thick, Thick) => "┿",
+        (Thin, Thin, Thick, Thin) => "┽",
+        (Thin, Thin, Thin, Thick) => "┾",
+        (Thick, Thin, Thin, Thin) => "╀",
+        (Thin, Thick, Thin, Thin) => "╁",
+        (Thick, Thick, Thin, Thin) => "╂",
+        (Thick, Thin, Thick, Thin) => "╃",
+        (Thick, Thin, Thin, Thick) => "╄",
+        (Thin, Thick, Thick, Thin) => "╅",
+        (Thin, Thick, Thin, Thick) => "╆",
+        (Thick, Thin, Thick, Thick) => "╇",
+        (Thin, Thick, Thick, Thick) => "╈",
+        (Thick, Thick, Thick, Thin) => "╉",
+        (Thick, Thick, Thin, Thick) => "╊",
+        // single mixed-weight straight segments (one half of a vertical or horizontal line at
+        // each weight, e.g. where a thin border hands off to a thick one mid-span)
+        (N, N, Thin, Thick) => "╼",
+        (Thin, Thick, N, N) => "╽",
+        (N, N, Thick, Thin) => "╾",
+        (Thick, Thin, N, N) => "╿",
+        // -- thin/thick corners (only one arm of each weight) --
+        (N, Thin, N, Thick) => "┍",
+        (N, Thin, Thick, N) => "┑",
+        (Thin, N, N, Thick) => "┕",
+        (Thin, N, Thick, N) => "┙",
+        (N, Thick, N, Thin) => "┎",
+        (N, Thick, Thin, N) => "┒",
+        (Thick, N, N, Thin) => "┖",
+        (Thick, N, Thin, N) => "┚",
+        // -- thin/double crossings: only the symmetric ones are listed --
+        (Thin, Thin, Double, Double) => "╪",
+        (Double, Double, Thin, Thin) => "╫",
+        // -- thin/double corners --
+        (N, Thin, N, Double) => "╒",
+        (N, Thin, Double, N) => "╕",
+        (Thin, N, N, Double) => "╘",
+        (Thin, N, Double, N) => "╛",
+        (N, Double, N, Thin) => "╓",
+        (N, Double, Thin, N) => "╖",
+        (Double, N, N, Thin) => "╙",
+        (Double, N, Thin, N) => "╜",
+        _ => return None,
+    })
+}
+
+/// The reverse of [`unicode_symbol_from_line_parts`]: given a glyph from the global Unicode table,
+/// decode which arms (and weights) it represents.
+fn reverse_unicode_lookup(symbol: &str) -> Option<LineParts> {
+    use Weight::{Double, None as N, Thick, Thin};
+    let (up, down, left, right) = match symbol {
+        "┌" => (N, Thin, N, Thin),
+        "┬" => (N, Thin, Thin, Thin),
+        "└" => (Thin, N, N, Thin),
+        "┐" => (N, Thin, Thin, N),
+        "┘" => (Thin, N, Thin, N),
+        "├" => (Thin, Thin, N, Thin),
+        "┤" => (Thin, Thin, Thin, N),
+        "┴" => (Thin, N, Thin, Thin),
+        "┼" => (Thin, Thin, Thin, Thin),
+        "┏" => (N, Thick, N, Thick),
+        "┗" => (Thick, N, N, Thick),
+        "┓" => (N, Thick, Thick, N),
+        "┛" => (Thick, N, Thick, N),
+        "┣" => (Thick, Thick, N, Thick),
+        "┫" => (Thick, Thick, Thick, N),
+        "┳" => (N, Thick, Thick, Thick),
+        "┻" => (Thick, N, Thick, Thick),
+        "╋" => (Thick, Thick, Thick, Thick),
+        "╔" => (N, Double, N, Double),
+        "╚" => (Double, N, N, Double),
+        "╗" => (N, Double, Double, N),
+        "╝" => (Double, N, Double, N),
+        "╠" => (Double, Double, N, Double),
+        "╣" => (Double, Double, Double, N),
+        "╦" => (N, Double, Double, Double),
+        "╩" => (Double, N, Double, Double),
+        "╬" => (Double, Double, Double, Double),
+        "┝" => (Thin, Thin, N, Thick),
+        "┞" => (Thick, Thin, N, Thin),
+        "┟" => (Thin, Thick, N, Thin),
+        "┠" => (Thick, Thick, N, Thin),
+        "┡" => (Thick, Thin, N, Thick),
+        "┢" => (Thin, Thick, N, Thick),
+        "┥" => (Thin, Thin, Thick, N),
+        "┦" => (Thick, Thin, Thin, N),
+        "┧" => (Thin, Thick, Thin, N),
+        "┨" => (Thick, Thick, Thin, N),
+        "┩" => (Thick, Thin, Thick, N),
+        "┪" => (Thin, Thick, Thick, N),
+        "┭" => (N, Thin, Thick, Thin),
+        "┮" => (N, Thin, Thin, Thick),
+        "┯" => (N, Thin, Thick, Thick),
+        "┰" => (N, Thick, Thin, Thin),
+        "┱" => (N, Thick, Thick, Thin),
+        "┲" => (N, Thick, Thin, Thick),
+        "┵" => (Thin, N, Thick, Thin),
+        "┶" => (Thin, N, Thin, Thick),
+        "┷" => (Thin, N, Thick, Thick),
+        "┸" => (Thick, N, Thin, Thin),
+        "┹" => (Thick, N, Thick, Thin),
+        "┺" => (Thick, N, Thin, Thick),
+        "┿" => (Thin, Thin, Thick, Thick),
+        "┽" => (Thin, Thin, Thick, Thin),
+        "┾" => (Thin, Thin, Thin, Thick),
+        "╀" => (Thick, Thin, Thin, Thin),
+        "╁" => (Thin, Thick, Thin, Thin),
+        "╂" => (Thick, Thick, Thin, Thin),
+        "╃" => (Thick, Thin, Thick, Thin),
+        "╄" => (Thick, Thin, Thin, Thick),
+        "╅" => (Thin, Thick, Thick, Thin),
+        "╆" => (Thin, Thick, Thin, Thick),
+        "╇" => (Thick, Thin, Thick, Thick),
+        "╈" => (Thin, Thick, Thick, Thick),
+        "╉" => (Thick, Thick, Thick, Thin),
+        "╊" => (Thick, Thick, Thin, Thick),
+        "╼" => (N, N, Thin, Thick),
+        "╽" => (Thin, Thick, N, N),
+        "╾" => (N, N, Thick, Thin),
+        "╿" => (Thick, Thin, N, N),
+        "┍" => (N, Thin, N, Thick),
+        "┑" => (N, Thin, Thick, N),
+        "┕" => (Thin, N, N, Thick),
+        "┙" => (Thin, N, Thick, N),
+        "┎" => (N, Thick, N, Thin),
+        "┒" => (N, Thick, Thin, N),
+        "┖" => (Thick, N, N, Thin),
+        "┚" => (Thick, N, Thin, N),
+        "╪" => (Thin, Thin, Double, Double),
+        "╫" => (Double, Double, Thin, Thin),
+        "╒" => (N, Thin, N, Double),
+        "╕" => (N, Thin, Double, N),
+        "╘" => (Thin, N, N, Double),
+        "╛" => (Thin, N, Double, N),
+        "╓" => (N, Double, N, Thin),
+        "╖" => (N, Double, Thin, N),
+        "╙" => (Double, N, N, Thin),
+        "╜" => (Double, N, Thin, N),
+        "│" => (Thin, Thin, N, N),
+        "─" => (N, N, Thin, Thin),
+        "║" => (Double, Double, N, N),
+        "═" => (N, N, Double, Double),
+        "┃" => (Thick, Thick, N, N),
+        "━" => (N, N, Thick, Thick),
+        _ => return None,
+    };
+    Some(LineParts { up, down, left, right })
+}
+
+/// A plain, simple border.
+///
+/// # Example
+///
+/// ```plain
+/// ┌───────┐
+/// │       │
+/// └───────┘
+/// ```
+pub const PLAIN: Set = Set {
+    top_left: "┌",
+    top_right: "┐",
+    bottom_left: "└",
+    bottom_right: "┘",
+    vertical_left: "│",
+    vertical_right: "│",
+    horizontal_top: "─",
+    horizontal_bottom: "─",
+    vertical_t_right: "├",
+    vertical_t_left: "┤",
+    horizontal_t_down: "┬",
+    horizontal_t_up: "┴",
+    cross: "┼",
+};
+
+/// A plain border with rounded corners.
+///
+/// # Example
+///
+/// ```plain
+/// ╭───────╮
+/// │       │
+/// ╰───────╯
+/// ```
+pub const ROUNDED: Set = Set {
+    top_left: "╭",
+    top_right: "╮",
+    bottom_left: "╰",
+    bottom_right: "╯",
+    ..PLAIN
+};
+
+/// A doubled border.
+///
+/// Note this uses one character that draws two lines.
+///
+/// # Example
+///
+/// ```plain
+/// ╔═══════╗
+/// ║       ║
+/// ╚═══════╝
+/// ```
+pub const DOUBLE: Set = Set {
+    top_left: "╔",
+    top_right: "╗",
+    bottom_left: "╚",
+    bottom_right: "╝",
+    vertical_left: "║",
+    vertical_right: "║",
+    horizontal_top: "═",
+    horizontal_bottom: "═",
+    vertical_t_right: "╠",
+    vertical_t_left: "╣",
+    horizontal_t_down: "╦",
+    horizontal_t_up: "╩",
+    cross: "╬",
+};
+
+/// A thick border.
+///
+/// # Example
+///
+/// ```plain
+/// ┏━━━━━━━┓
+/// ┃       ┃
+/// ┗━━━━━━━┛
+/// ```
+pub const THICK: Set = Set {
+    top_left: "┏",
+    top_right: "┓",
+    bottom_left: "┗",
+    bottom_right: "┛",
+    vertical_left: "┃",
+    vertical_right: "┃",
+    horizontal_top: "━",
+    horizontal_bottom: "━",
+    vertical_t_right: "┣",
+    vertical_t_left: "┫",
+    horizontal_t_down: "┳",
+    horizontal_t_up: "┻",
+    cross: "╋",
+};
+
+/// A border with a single line on the inside of a half block.
+///
+/// # Example
+///
+/// ```plain
+/// ▗▄▄▄▄▄▄▄▖
+/// ▐       ▌
+/// ▐       ▌
+/// ▝▀▀▀▀▀▀▀▘
+/// ```
+pub const QUADRANT_INSIDE: Set = Set {
+    top_left: "▗",
+    top_right: "▖",
+    bottom_left: "▝",
+    bottom_right: "▘",
+    vertical_left: "▐",
+    vertical_right: "▌",
+    horizontal_top: "▄",
+    horizontal_bottom: "▀",
+    vertical_t_right: "█",
+    vertical_t_left: "█",
+    horizontal_t_down: "█",
+    horizontal_t_up: "█",
+    cross: "█",
+};
+
+/// A border with a single line on the outside of a half block.
+///
+/// # Example
+///
+/// ```plain
+/// ▛▀▀▀▀▀▀▀▜
+/// ▌       ▐
+/// ▌       ▐
+/// ▙▄▄▄▄▄▄▄▟
+/// ```
+pub const QUADRANT_OUTSIDE: Set = Set {
+    top_left: "▛",
+    top_right: "▜",
+    bottom_left: "▙",
+    bottom_right: "▟",
+    vertical_left: "▌",
+    vertical_right: "▐",
+    horizontal_top: "▀",
+    horizontal_bottom: "▄",
+    vertical_t_right: "█",
+    vertical_t_left: "█",
+    horizontal_t_down: "█",
+    horizontal_t_up: "█",
+    cross: "█",
+};
@@ -0,0 +1,3 @@
+//! Symbols used to render various widgets, including borders.
+
+pub mod border;
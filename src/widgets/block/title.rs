@@ -0,0 +1,102 @@
+use strum::{Display, EnumString};
+
+use crate::prelude::*;
+
+/// A title for a [`Block`](crate::widgets::Block).
+///
+/// It can be aligned to the left, center, or right of the block, and can be positioned on the
+/// top or bottom (or, for titles that run down the border column, on the left or right) of the
+/// block.
+///
+/// You can provide the title with any [`Into<Line>`] type, such as a [`&str`], [`String`],
+/// [`Span`], or [`Line`].
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{prelude::*, widgets::block::*};
+///
+/// Title::from("Title");
+/// Title::from(Line::from("Title"));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Title<'a> {
+    /// Title content
+    pub content: Line<'a>,
+    /// Title alignment
+    ///
+    /// If [`None`], defaults to the alignment of the [`Block`](crate::widgets::Block).
+    pub alignment: Option<Alignment>,
+    /// Title position
+    ///
+    /// If [`None`], defaults to the position of the [`Block`](crate::widgets::Block).
+    pub position: Option<Position>,
+}
+
+/// Defines the position of a [`Title`] within the [`Block`](crate::widgets::Block).
+///
+/// `Top` and `Bottom` run along a horizontal border and are laid out left to right. `Left` and
+/// `Right` run along a vertical border and are laid out top to bottom, one grapheme per row.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Position {
+    /// Position the title at the top of the block.
+    #[default]
+    Top,
+    /// Position the title at the bottom of the block.
+    Bottom,
+    /// Position the title along the left border of the block, running top to bottom.
+    Left,
+    /// Position the title along the right border of the block, running top to bottom.
+    Right,
+}
+
+/// Controls what happens when a [`Title`] (or, for centered titles, the combined run of every
+/// title sharing an edge and alignment) doesn't fit in the space available for it on a
+/// [`Block`](crate::widgets::Block)'s border.
+///
+/// Set through [`Block::title_overflow`](crate::widgets::Block::title_overflow). Whichever
+/// policy is chosen, a title is never cut in the middle of a multi-column grapheme.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TitleOverflow {
+    /// Hard cut the title at the edge of the available span. This is the default.
+    #[default]
+    Clip,
+    /// Reserve one cell at each edge of the title that's actually cut off and fill it with
+    /// `char`, e.g. `'…'`, instead of the content that would otherwise be hidden there.
+    Ellipsis(char),
+}
+
+impl<'a> Title<'a> {
+    /// Set the content of the title
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn content<T>(mut self, content: T) -> Title<'a>
+    where
+        T: Into<Line<'a>>,
+    {
+        self.content = content.into();
+        self
+    }
+
+    /// Set the alignment of the title
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn alignment(mut self, alignment: Alignment) -> Title<'a> {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Set the position of the title
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn position(mut self, position: Position) -> Title<'a> {
+        self.position = Some(position);
+        self
+    }
+}
+
+impl<'a, T> From<T> for Title<'a>
+where
+    T: Into<Line<'a>>,
+{
+    fn from(value: T) -> Self {
+        Self::default().content(value)
+    }
+}